@@ -1,14 +1,49 @@
 //! Various helpers for teeing readers and writers.
 //!
-//! [`TeeReader`] and [`TeeWriter`] respect the underlying `Read`er and `Write`er's method overrides.
-//! (Except for vectored, because I haven't got around to that yet)
-//! 
+//! [`TeeReader`] and [`TeeWriter`] respect the underlying `Read`er and `Write`er's method overrides,
+//! including vectored reads and writes.
+//!
 //! [`TeeReader`] supports teeing `Read`, `BufRead` and `Seek` readers.
+//!
+//! With the `core_io` feature enabled, this crate is built as `#![no_std]` against
+//! [`no_std_io`], a small hand-rolled stand-in for the subset of `std::io` this crate
+//! needs, for use on embedded targets. (An earlier version of this feature depended on the
+//! long-unmaintained `core_io` crate; that crate's build script and its own
+//! `#![feature(...)]` gates only ever targeted pre-2019 nightlies and it no longer builds on
+//! any current rustc, so we no longer depend on it.) In that configuration there is no
+//! `Stderr`, so `tee_dbg`/`new_stderr` are unavailable, and the `no_std_io` traits have no
+//! `Vec`/`String`-based methods (`read_to_end`, `read_until`, `read_line`) and no vectored I/O,
+//! so the tee types provide none of these there.
+//!
+//! [`ReadExt`] and [`WriteExt`] add `.tee(...)` (and friends) to any `Read`/`Write`er.
+
+#![cfg_attr(feature = "core_io", no_std)]
+
+/// A minimal hand-rolled stand-in for `std::io`'s `Read`/`Write`/`BufRead`/`Seek` surface,
+/// used in place of `std::io` when the `core_io` feature is enabled. Public so that
+/// downstream `no_std` readers/writers can implement these traits to work with
+/// [`TeeReader`]/[`TeeWriter`] and friends.
+#[cfg(feature = "core_io")]
+pub mod no_std_io;
+
+#[cfg(not(feature = "core_io"))]
+use std::io as ioimpl;
+#[cfg(feature = "core_io")]
+use no_std_io as ioimpl;
+
+use core::fmt::Arguments;
+use ioimpl::{BufRead, Read, Seek, Write};
+#[cfg(not(feature = "core_io"))]
+use ioimpl::{IoSlice, IoSliceMut, Stderr};
 
-use std::{fmt::Arguments, io::{BufRead, Read, Seek, Stderr, Write}};
-trait ReadExt: Read {
+pub trait ReadExt: Read {
     fn tee<W: Write>(self, out: W) -> TeeReader<Self, W> where Self: Sized;
+    #[cfg(not(feature = "core_io"))]
     fn tee_dbg(self) -> TeeReader<Self, Stderr> where Self: Sized;
+
+    /// Tees only the first `limit` bytes read to `out`, then passes the remainder through
+    /// untouched. See [`TeeTake`].
+    fn tee_take<W: Write>(self, out: W, limit: u64) -> TeeTake<Self, W> where Self: Sized;
 }
 
 impl<R: Read> ReadExt for R {
@@ -16,13 +51,19 @@ impl<R: Read> ReadExt for R {
         TeeReader::new(self, out)
     }
 
+    #[cfg(not(feature = "core_io"))]
     fn tee_dbg(self) -> TeeReader<Self, Stderr> where Self: Sized {
         TeeReader::new_stderr(self)
     }
+
+    fn tee_take<W: Write>(self, out: W, limit: u64) -> TeeTake<Self, W> where Self: Sized {
+        TeeTake::new(self, out, limit)
+    }
 }
 
-trait WriteExt: Write {
+pub trait WriteExt: Write {
     fn tee<R: Write>(self, other: R) -> TeeWriter<Self, R> where Self: Sized;
+    #[cfg(not(feature = "core_io"))]
     fn tee_dbg(self) -> TeeWriter<Self, Stderr> where Self: Sized;
 }
 
@@ -31,6 +72,7 @@ impl<W: Write> WriteExt for W {
         TeeWriter::new(self, other)
     }
 
+    #[cfg(not(feature = "core_io"))]
     fn tee_dbg(self) -> TeeWriter<Self, Stderr> where Self: Sized {
         TeeWriter::new_stderr(self)
     }
@@ -51,6 +93,7 @@ impl<R: Read, W: Write> TeeReader<R, W> {
     }
 }
 
+#[cfg(not(feature = "core_io"))]
 impl<R: Read> TeeReader<R, Stderr> {
     pub fn new_stderr(reader: R) -> Self {
         Self {
@@ -83,16 +126,34 @@ impl<R, W> TeeReader<R, W> {
 }
 
 impl<R: Read, W: Write> Read for TeeReader<R, W> {
-    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    fn read(&mut self, buf: &mut [u8]) -> ioimpl::Result<usize> {
         let len = self.reader.read(buf)?;
         self.writer.write_all(&buf[..len])?;
         Ok(len)
     }
 
-    // read_vectored omitted
-    //TODO ?
+    #[cfg(not(feature = "core_io"))]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> ioimpl::Result<usize> {
+        let len = self.reader.read_vectored(bufs)?;
+        let mut remaining = len;
+        for buf in bufs.iter() {
+            if remaining == 0 {
+                break;
+            }
+            let n = remaining.min(buf.len());
+            self.writer.write_all(&buf[..n])?;
+            remaining -= n;
+        }
+        Ok(len)
+    }
 
-    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+    // is_read_vectored omitted: `can_vector` is nightly-only, so we can't
+    // forward it without destabilising the whole crate.
+
+    // Only built with `std`: `no_std_io::Read` has no `read_to_end` (it needs
+    // an allocator the `core_io` configuration doesn't assume).
+    #[cfg(not(feature = "core_io"))]
+    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> ioimpl::Result<usize> {
         let start = buf.len();
         let len = self.reader.read_to_end(buf)?;
         self.writer.write_all(&buf[start..start + len])?;
@@ -102,17 +163,17 @@ impl<R: Read, W: Write> Read for TeeReader<R, W> {
     // read_to_string omitted
     // The default impl calls `read_to_end` anyway.
 
-    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+    fn read_exact(&mut self, buf: &mut [u8]) -> ioimpl::Result<()> {
         self.reader.read_exact(buf)?;
-        self.writer.write_all(&buf)?;
+        self.writer.write_all(buf)?;
         Ok(())
     }
 
-    // by_ref omitted  
+    // by_ref omitted
 }
 
 impl<R: BufRead, W: Write> BufRead for TeeReader<R, W> {
-    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+    fn fill_buf(&mut self) -> ioimpl::Result<&[u8]> {
         self.reader.fill_buf()
     }
 
@@ -120,15 +181,19 @@ impl<R: BufRead, W: Write> BufRead for TeeReader<R, W> {
         self.reader.consume(amt)
     }
 
-    fn read_until(&mut self, byte: u8, buf: &mut Vec<u8>) -> std::io::Result<usize> {
+    // Only built with `std`: `no_std_io::BufRead` has no `read_until`/`read_line`
+    // (they need an allocator the `core_io` configuration doesn't assume).
+    #[cfg(not(feature = "core_io"))]
+    fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> ioimpl::Result<usize> {
         let initial_len = buf.len();
         let bytes_read = self.reader.read_until(byte, buf)?;
         self.writer.write_all(&buf[initial_len..initial_len + bytes_read])?;
         Ok(bytes_read)
     }
 
-    fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
-        let initial_len = buf.as_bytes().len();
+    #[cfg(not(feature = "core_io"))]
+    fn read_line(&mut self, buf: &mut std::string::String) -> ioimpl::Result<usize> {
+        let initial_len = buf.len();
         let bytes_read = self.reader.read_line(buf)?;
         self.writer.write_all(&buf.as_bytes()[initial_len..initial_len + bytes_read])?;
         Ok(bytes_read)
@@ -136,31 +201,232 @@ impl<R: BufRead, W: Write> BufRead for TeeReader<R, W> {
 }
 
 impl<R: Seek, W> Seek for TeeReader<R, W> {
-    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+    fn seek(&mut self, pos: ioimpl::SeekFrom) -> ioimpl::Result<u64> {
         self.reader.seek(pos)
     }
 
-    fn stream_position(&mut self) -> std::io::Result<u64> {
+    fn stream_position(&mut self) -> ioimpl::Result<u64> {
         self.reader.stream_position()
     }
 }
 
-struct TeeWriter<L, R> {
+/// A [`TeeReader`] variant whose writer is also seekable.
+///
+/// Plain [`TeeReader`] only seeks the underlying reader, so a seekable tee writer (e.g. a
+/// file you want to keep byte-aligned with the reader) drifts out of sync after a seek. This
+/// type additionally seeks the writer to `SeekFrom::Start(p)` whenever the reader is seeked
+/// to position `p`, so writes continue to land at the reader's post-seek stream position,
+/// matching the behavior of the `tees` crate's seekable tee.
+pub struct SeekTeeReader<R, W> {
+    inner: TeeReader<R, W>,
+}
+
+impl<R: Read + Seek, W: Write + Seek> SeekTeeReader<R, W> {
+    pub fn new(reader: R, writer: W) -> Self {
+        Self {
+            inner: TeeReader::new(reader, writer),
+        }
+    }
+}
+
+impl<R, W> SeekTeeReader<R, W> {
+    pub fn reader_ref(&self) -> &R {
+        self.inner.reader_ref()
+    }
+
+    pub fn reader_mut(&mut self) -> &mut R {
+        self.inner.reader_mut()
+    }
+
+    pub fn writer_ref(&self) -> &W {
+        self.inner.writer_ref()
+    }
+
+    pub fn writer_mut(&mut self) -> &mut W {
+        self.inner.writer_mut()
+    }
+
+    pub fn into_reader_writer(self) -> (R, W) {
+        self.inner.into_reader_writer()
+    }
+}
+
+impl<R: Read, W: Write> Read for SeekTeeReader<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> ioimpl::Result<usize> {
+        self.inner.read(buf)
+    }
+
+    #[cfg(not(feature = "core_io"))]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> ioimpl::Result<usize> {
+        self.inner.read_vectored(bufs)
+    }
+
+    #[cfg(not(feature = "core_io"))]
+    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> ioimpl::Result<usize> {
+        self.inner.read_to_end(buf)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> ioimpl::Result<()> {
+        self.inner.read_exact(buf)
+    }
+}
+
+impl<R: BufRead, W: Write> BufRead for SeekTeeReader<R, W> {
+    fn fill_buf(&mut self) -> ioimpl::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+
+    #[cfg(not(feature = "core_io"))]
+    fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> ioimpl::Result<usize> {
+        self.inner.read_until(byte, buf)
+    }
+
+    #[cfg(not(feature = "core_io"))]
+    fn read_line(&mut self, buf: &mut std::string::String) -> ioimpl::Result<usize> {
+        self.inner.read_line(buf)
+    }
+}
+
+impl<R: Seek, W: Write + Seek> Seek for SeekTeeReader<R, W> {
+    fn seek(&mut self, pos: ioimpl::SeekFrom) -> ioimpl::Result<u64> {
+        let p = self.inner.reader.seek(pos)?;
+        self.inner.writer.seek(ioimpl::SeekFrom::Start(p))?;
+        Ok(p)
+    }
+}
+
+/// A reader which tees only the first `limit` bytes of its input to another writer, then
+/// passes the remainder through untouched.
+///
+/// Useful for capturing just a header, or the first N bytes of a stream, for inspection while
+/// streaming the rest with zero extra copies. Created by [`ReadExt::tee_take`].
+pub struct TeeTake<R, W> {
+    reader: R,
+    writer: W,
+    remaining: u64,
+}
+
+impl<R: Read, W: Write> TeeTake<R, W> {
+    pub fn new(reader: R, writer: W, limit: u64) -> Self {
+        Self {
+            reader,
+            writer,
+            remaining: limit,
+        }
+    }
+}
+
+impl<R, W> TeeTake<R, W> {
+    pub fn reader_ref(&self) -> &R {
+        &self.reader
+    }
+
+    pub fn reader_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    pub fn writer_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn writer_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    pub fn into_reader_writer(self) -> (R, W) {
+        (self.reader, self.writer)
+    }
+}
+
+impl<R: Read, W: Write> Read for TeeTake<R, W> {
+    fn read(&mut self, buf: &mut [u8]) -> ioimpl::Result<usize> {
+        let len = self.reader.read(buf)?;
+        if self.remaining > 0 {
+            let n = self.remaining.min(len as u64) as usize;
+            self.writer.write_all(&buf[..n])?;
+            self.remaining -= n as u64;
+        }
+        Ok(len)
+    }
+}
+
+impl<R: BufRead, W: Write> BufRead for TeeTake<R, W> {
+    fn fill_buf(&mut self) -> ioimpl::Result<&[u8]> {
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.reader.consume(amt)
+    }
+
+    // The default `read_until`/`read_line` are implemented purely in terms of
+    // `fill_buf`/`consume`, so without these overrides nothing would ever get teed.
+    // As with `TeeReader`, these are only built with `std`: `no_std_io::BufRead` has no
+    // `read_until`/`read_line` (they need an allocator the `core_io` configuration doesn't assume).
+    #[cfg(not(feature = "core_io"))]
+    fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> ioimpl::Result<usize> {
+        let initial_len = buf.len();
+        let bytes_read = self.reader.read_until(byte, buf)?;
+        if self.remaining > 0 {
+            let n = self.remaining.min(bytes_read as u64) as usize;
+            self.writer.write_all(&buf[initial_len..initial_len + n])?;
+            self.remaining -= n as u64;
+        }
+        Ok(bytes_read)
+    }
+
+    #[cfg(not(feature = "core_io"))]
+    fn read_line(&mut self, buf: &mut std::string::String) -> ioimpl::Result<usize> {
+        let initial_len = buf.len();
+        let bytes_read = self.reader.read_line(buf)?;
+        if self.remaining > 0 {
+            let n = self.remaining.min(bytes_read as u64) as usize;
+            self.writer.write_all(&buf.as_bytes()[initial_len..initial_len + n])?;
+            self.remaining -= n as u64;
+        }
+        Ok(bytes_read)
+    }
+}
+
+/// A writer which tees its input to another writer.
+pub struct TeeWriter<L, R> {
     left: L,
     right: R,
 }
 
 impl<L: Write, R: Write> TeeWriter<L, R> {
-    fn new(left: L, right: R) -> Self {
+    pub fn new(left: L, right: R) -> Self {
         Self {
             left,
             right,
         }
     }
+
+    // `Write::write_all_vectored` is still nightly-only (`write_all_vectored`
+    // feature), so this is exposed as an inherent method instead of a trait
+    // override until it stabilises. Unavailable under `core_io`, whose
+    // `no_std_io` traits have no vectored I/O.
+    #[cfg(not(feature = "core_io"))]
+    pub fn write_all_vectored(&mut self, mut bufs: &mut [IoSlice<'_>]) -> ioimpl::Result<()> {
+        while !bufs.is_empty() {
+            match self.write_vectored(bufs) {
+                Ok(0) => return Err(ioimpl::Error::new(ioimpl::ErrorKind::WriteZero, "failed to write whole buffer")),
+                Ok(n) => IoSlice::advance_slices(&mut bufs, n),
+                Err(e) if e.kind() == ioimpl::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
 }
 
+#[cfg(not(feature = "core_io"))]
 impl<L: Write> TeeWriter<L, Stderr> {
-    fn new_stderr(left: L) -> Self {
+    pub fn new_stderr(left: L) -> Self {
         Self {
             left,
             right: std::io::stderr(),
@@ -169,29 +435,43 @@ impl<L: Write> TeeWriter<L, Stderr> {
 }
 
 impl<L: Write, R: Write> Write for TeeWriter<L, R> {
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        let n = self.left.write(&buf[..])?;
+    fn write(&mut self, buf: &[u8]) -> ioimpl::Result<usize> {
+        let n = self.left.write(buf)?;
         self.right.write_all(&buf[..n])?;
         Ok(n)
     }
 
-    fn flush(&mut self) -> std::io::Result<()> {
+    fn flush(&mut self) -> ioimpl::Result<()> {
         self.left.flush()?;
         self.right.flush()?;
         Ok(())
     }
 
-    // write_vectored omitted
+    #[cfg(not(feature = "core_io"))]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> ioimpl::Result<usize> {
+        let n = self.left.write_vectored(bufs)?;
+        let mut remaining = n;
+        for buf in bufs {
+            if remaining == 0 {
+                break;
+            }
+            let written = remaining.min(buf.len());
+            self.right.write_all(&buf[..written])?;
+            remaining -= written;
+        }
+        Ok(n)
+    }
+
+    // is_write_vectored omitted: `can_vector` is nightly-only, so we can't
+    // forward it without destabilising the whole crate.
 
-    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+    fn write_all(&mut self, buf: &[u8]) -> ioimpl::Result<()> {
         self.left.write_all(buf)?;
         self.right.write_all(buf)?;
         Ok(())
     }
 
-    // write_all_vectored omitted
-
-    fn write_fmt(&mut self, fmt: Arguments<'_>) -> std::io::Result<()> {
+    fn write_fmt(&mut self, fmt: Arguments<'_>) -> ioimpl::Result<()> {
         self.left.write_fmt(fmt)?;
         self.right.write_fmt(fmt)?;
         Ok(())
@@ -200,11 +480,64 @@ impl<L: Write, R: Write> Write for TeeWriter<L, R> {
     // by_ref omitted
 }
 
+/// A writer that fans a single stream of writes out to any number of sinks.
+///
+/// Unlike [`TeeWriter`], which tees to exactly two sinks, `BroadcastWriter` owns a `Vec` of
+/// writers and mirrors every write to all of them, succeeding only once every sink has
+/// accepted the bytes.
+#[cfg(not(feature = "core_io"))]
+pub struct BroadcastWriter<W> {
+    writers: std::vec::Vec<W>,
+}
+
+#[cfg(not(feature = "core_io"))]
+impl<W: Write> BroadcastWriter<W> {
+    pub fn new(writers: std::vec::Vec<W>) -> Self {
+        Self { writers }
+    }
+
+    /// Adds another sink to the broadcast set.
+    pub fn push(&mut self, writer: W) {
+        self.writers.push(writer);
+    }
+
+    pub fn writers_mut(&mut self) -> &mut [W] {
+        &mut self.writers
+    }
+}
+
+#[cfg(not(feature = "core_io"))]
+impl<W: Write> Write for BroadcastWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> ioimpl::Result<usize> {
+        // Use `write_all` per sink rather than reporting the minimum accepted count, so a
+        // short write on one sink can't silently desync the others.
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> ioimpl::Result<()> {
+        let mut first_err = None;
+        for writer in &mut self.writers {
+            if let Err(e) = writer.flush() {
+                first_err.get_or_insert(e);
+            }
+        }
+        first_err.map_or(Ok(()), Err)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> ioimpl::Result<()> {
+        for writer in &mut self.writers {
+            writer.write_all(buf)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::io::{BufRead, Read};
+    use std::io::{BufRead, Cursor, IoSlice, IoSliceMut, Read, Seek, SeekFrom, Write};
 
-    use crate::TeeReader;
+    use crate::{BroadcastWriter, ReadExt, SeekTeeReader, TeeReader, TeeWriter};
 
     #[test]
     fn basic_read() -> std::io::Result<()> {
@@ -214,7 +547,7 @@ mod tests {
 
         let mut reader = TeeReader::<&[u8], &mut [u8]>::new(text, &mut debug_buf);
         assert_eq!(reader.read(&mut buf)?, 5);
-        drop(reader);
+        let _ = reader;
 
         assert_eq!(buf, debug_buf);
         Ok(())
@@ -227,7 +560,7 @@ mod tests {
         let mut debug_buf = [0u8; 14];
         let mut reader = TeeReader::<&[u8], &mut [u8]>::new(text, &mut debug_buf);
         assert_eq!(reader.read_to_end(&mut buf)?, 14);
-        drop(reader);
+        let _ = reader;
         assert_eq!(buf, debug_buf);
         Ok(())
     }
@@ -241,8 +574,151 @@ mod tests {
         assert_eq!(reader.read_until(b',', &mut buf)?, 6);
         let mut string = String::new();
         assert_eq!(reader.read_line(&mut string)?, 8);
-        drop(reader);
+        let _ = reader;
         assert_eq!(&debug_buf, text);
         Ok(())
     }
+
+    #[test]
+    fn read_vectored() -> std::io::Result<()> {
+        let text = b"Hello, world!";
+        let mut debug_buf = [0u8; 13];
+        let mut reader = TeeReader::<&[u8], &mut [u8]>::new(text, &mut debug_buf);
+
+        let mut a = [0u8; 5];
+        let mut b = [0u8; 8];
+        let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+        assert_eq!(reader.read_vectored(&mut bufs)?, 13);
+        let _ = reader;
+
+        assert_eq!(&debug_buf[..5], &a);
+        assert_eq!(&debug_buf[5..], &b);
+        Ok(())
+    }
+
+    #[test]
+    fn write_vectored() -> std::io::Result<()> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut writer = TeeWriter::new(&mut left, &mut right);
+
+        let a = b"Hello, ";
+        let b = b"world!";
+        let bufs = [IoSlice::new(a), IoSlice::new(b)];
+        assert_eq!(writer.write_vectored(&bufs)?, 13);
+        let _ = writer;
+
+        assert_eq!(left, b"Hello, world!");
+        assert_eq!(right, b"Hello, world!");
+        Ok(())
+    }
+
+    #[test]
+    fn write_all_vectored() -> std::io::Result<()> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        let mut writer = TeeWriter::new(&mut left, &mut right);
+
+        let a = b"Hello, ";
+        let b = b"world!";
+        let mut bufs = [IoSlice::new(a), IoSlice::new(b)];
+        writer.write_all_vectored(&mut bufs)?;
+        let _ = writer;
+
+        assert_eq!(left, b"Hello, world!");
+        assert_eq!(right, b"Hello, world!");
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_write() -> std::io::Result<()> {
+        let mut a = Vec::new();
+        let mut b = Vec::new();
+        let mut c = Vec::new();
+        let mut writer = BroadcastWriter::new(vec![&mut a, &mut b, &mut c]);
+        writer.write_all(b"Hello, world!")?;
+        let _ = writer;
+
+        assert_eq!(a, b"Hello, world!");
+        assert_eq!(b, b"Hello, world!");
+        assert_eq!(c, b"Hello, world!");
+        Ok(())
+    }
+
+    #[test]
+    fn broadcast_write_push() -> std::io::Result<()> {
+        let mut a = Vec::new();
+        let mut writer = BroadcastWriter::new(vec![&mut a]);
+
+        let mut b = Vec::new();
+        writer.push(&mut b);
+        writer.write_all(b"Hello!")?;
+        let _ = writer;
+
+        assert_eq!(a, b"Hello!");
+        assert_eq!(b, b"Hello!");
+        Ok(())
+    }
+
+    #[test]
+    fn seek_tee_mirrors_writer_position() -> std::io::Result<()> {
+        let text = b"Hello, world!";
+        let mut writer = Cursor::new(vec![0u8; text.len()]);
+        let mut reader = SeekTeeReader::new(Cursor::new(text), &mut writer);
+
+        reader.seek(SeekFrom::Start(7))?;
+        let mut buf = [0u8; 6];
+        reader.read_exact(&mut buf)?;
+        let _ = reader;
+
+        assert_eq!(&buf, b"world!");
+        assert_eq!(writer.position(), 13);
+        assert_eq!(writer.into_inner(), b"\0\0\0\0\0\0\0world!");
+        Ok(())
+    }
+
+    #[test]
+    fn tee_take_stops_teeing_past_limit() -> std::io::Result<()> {
+        let text: &[u8] = b"Hello, world!";
+        let mut tee_buf = Vec::new();
+        let mut reader = text.tee_take(&mut tee_buf, 5);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        let _ = reader;
+
+        assert_eq!(buf, text);
+        assert_eq!(tee_buf, b"Hello");
+        Ok(())
+    }
+
+    #[test]
+    fn tee_take_splits_a_single_read() -> std::io::Result<()> {
+        let text: &[u8] = b"Hello, world!";
+        let mut tee_buf = Vec::new();
+        let mut reader = text.tee_take(&mut tee_buf, 5);
+
+        let mut buf = [0u8; 13];
+        assert_eq!(reader.read(&mut buf)?, 13);
+        let _ = reader;
+
+        assert_eq!(&buf, text);
+        assert_eq!(tee_buf, b"Hello");
+        Ok(())
+    }
+
+    #[test]
+    fn tee_take_read_line() -> std::io::Result<()> {
+        let text: &[u8] = b"Hello, world!\n";
+        let mut tee_buf = Vec::new();
+        let mut reader = text.tee_take(&mut tee_buf, 5);
+
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        let _ = reader;
+
+        assert_eq!(line, "Hello, world!\n");
+        assert_eq!(tee_buf, b"Hello");
+        Ok(())
+    }
 }